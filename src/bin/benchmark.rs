@@ -1,56 +1,115 @@
 //! Benchmark binary for MLE evaluation algorithms
 //!
-//! Measures evaluation time across different dimensions and generates plots.
+//! Sweeps every implemented `EvaluationType` across a range of dimensions,
+//! running repetitions in parallel across a configurable number of worker
+//! threads, and records per-(dim, strategy) mean and standard deviation
+//! instead of just the mean. One line series per strategy is overlaid on
+//! the generated chart, and the raw per-run records are dumped to a JSON
+//! file alongside it so runs are machine-comparable.
 
 use std::fs;
+use std::sync::mpsc;
+use std::thread;
 use std::time::{Duration, Instant};
 
 use ark_ff::UniformRand;
 use ark_test_curves::bls12_381::Fr;
 use rand::thread_rng;
+use serde::Serialize;
 
 use plotters::prelude::*;
 
 use multilinear_extensions::multilinear::mle::{DenseOracle, EvaluationType, MultilinearExtension};
 use multilinear_extensions::multilinear::traits::MLE;
 
-/// Result of benchmarking a single dimension
-struct BenchResult {
-    dim: usize,
-    avg_time_ms: f64,
+// EvaluationType::Ramakrishna is still a todo!() in mle.rs, so it's left
+// out of the sweep until it's implemented.
+const STRATEGIES: [EvaluationType; 3] = [
+    EvaluationType::Naive,
+    EvaluationType::Zhu,
+    EvaluationType::Rothblum,
+];
+
+const COLORS: [RGBColor; 3] = [BLUE, RED, GREEN];
+
+fn strategy_name(strategy: EvaluationType) -> &'static str {
+    match strategy {
+        EvaluationType::Naive => "Naive",
+        EvaluationType::Zhu => "Zhu",
+        EvaluationType::Rothblum => "Rothblum",
+        EvaluationType::Ramakrishna => "Ramakrishna",
+    }
 }
 
-/// Run benchmark for a single dimension, averaging over multiple runs
-fn bench_dimension(dim: usize, num_runs: usize, strategy: EvaluationType) -> BenchResult {
-    let mut rng = thread_rng();
-    let mut total_time = Duration::ZERO;
-
-    for _ in 0..num_runs {
-        // Create fresh oracle for each run
-        let oracle = DenseOracle::<Fr>::new_rand(dim, &mut rng);
-
-        // Create random evaluation point
-        let z: Vec<Fr> = (0..dim).map(|_| Fr::rand(&mut rng)).collect();
+/// One (dim, strategy) record: mean and standard deviation in milliseconds
+/// over `samples_ms.len()` repetitions, plus the raw per-run timings.
+#[derive(Serialize)]
+struct BenchRecord {
+    dim: usize,
+    strategy: String,
+    mean_ms: f64,
+    stddev_ms: f64,
+    samples_ms: Vec<f64>,
+}
 
-        // Create MLE
-        let mle = MultilinearExtension::new(oracle, dim, strategy);
+/// Run `num_runs` repetitions of `dim`/`strategy` split across `num_workers`
+/// threads, and return every individual timing in milliseconds.
+fn bench_dimension(
+    dim: usize,
+    strategy: EvaluationType,
+    num_runs: usize,
+    num_workers: usize,
+) -> Vec<f64> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for worker in 0..num_workers {
+            let tx = tx.clone();
+            let runs_for_worker = num_runs / num_workers + usize::from(worker < num_runs % num_workers);
+
+            scope.spawn(move || {
+                let mut rng = thread_rng();
+                for _ in 0..runs_for_worker {
+                    // Create fresh oracle for each run
+                    let oracle = DenseOracle::<Fr>::new_rand(dim, &mut rng);
+
+                    // Create random evaluation point
+                    let z: Vec<Fr> = (0..dim).map(|_| Fr::rand(&mut rng)).collect();
+
+                    // Create MLE
+                    let mle = MultilinearExtension::new(oracle, dim, strategy);
+
+                    // Time the evaluation
+                    let start = Instant::now();
+                    let _result = mle.evaluate(&z).expect("Failed to evaluate");
+                    tx.send(start.elapsed()).expect("benchmark receiver dropped");
+                }
+            });
+        }
 
-        // Time the evaluation
-        let start = Instant::now();
-        let _result = mle.evaluate(&z).expect("Failed to evaluate");
-        total_time += start.elapsed();
-    }
+        drop(tx);
+    });
 
-    let avg_time_ms = total_time.as_secs_f64() * 1000.0 / num_runs as f64;
+    rx.into_iter()
+        .map(|elapsed: Duration| elapsed.as_secs_f64() * 1000.0)
+        .collect()
+}
 
-    BenchResult { dim, avg_time_ms }
+/// mean and (population) standard deviation of a set of samples
+fn mean_and_stddev(samples: &[f64]) -> (f64, f64) {
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+    (mean, variance.sqrt())
 }
 
-/// Generate a line chart of timing results
-fn generate_chart(results: &[BenchResult], title: &str, output_path: &str) {
-    let max_time = results.iter().map(|r| r.avg_time_ms).fold(0.0, f64::max);
-    let min_dim = results.first().map(|r| r.dim).unwrap_or(0);
-    let max_dim = results.last().map(|r| r.dim).unwrap_or(20);
+/// Generate a line chart with one series per strategy
+fn generate_chart(records: &[BenchRecord], dims: &[usize], title: &str, output_path: &str) {
+    let max_time = records
+        .iter()
+        .map(|r| r.mean_ms + r.stddev_ms)
+        .fold(0.0, f64::max);
+    let min_dim = *dims.first().unwrap_or(&0);
+    let max_dim = *dims.last().unwrap_or(&20);
 
     let root = BitMapBackend::new(output_path, (1600, 1200)).into_drawing_area();
     root.fill(&WHITE).unwrap();
@@ -76,24 +135,29 @@ fn generate_chart(results: &[BenchResult], title: &str, output_path: &str) {
         .draw()
         .unwrap();
 
-    // Draw the line
-    let data: Vec<(f64, f64)> = results
-        .iter()
-        .map(|r| (r.dim as f64, r.avg_time_ms))
-        .collect();
-
-    chart
-        .draw_series(LineSeries::new(data.clone(), &BLUE))
-        .unwrap()
-        .label("Naive")
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
-
-    // Draw points
-    chart
-        .draw_series(PointSeries::of_element(data, 5, &BLUE, &|c, s, st| {
-            Circle::new(c, s, st.filled())
-        }))
-        .unwrap();
+    for (i, &strategy) in STRATEGIES.iter().enumerate() {
+        let name = strategy_name(strategy);
+        let color = COLORS[i % COLORS.len()];
+
+        let data: Vec<(f64, f64)> = records
+            .iter()
+            .filter(|r| r.strategy == name)
+            .map(|r| (r.dim as f64, r.mean_ms))
+            .collect();
+
+        chart
+            .draw_series(LineSeries::new(data.clone(), &color))
+            .unwrap()
+            .label(name)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+
+        // Draw points
+        chart
+            .draw_series(PointSeries::of_element(data, 5, &color, &|c, s, st| {
+                Circle::new(c, s, st.filled())
+            }))
+            .unwrap();
+    }
 
     chart
         .configure_series_labels()
@@ -140,30 +204,65 @@ fn main() {
     let min_dim = 4;
     let max_dim = 20;
     let num_runs = 5;
+    let num_workers = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
 
     let bench_num = next_benchmark_number();
-    let output_path = format!("benchmarks/benchmark_{:03}.png", bench_num);
-
-    println!("Benchmarking MLE evaluation (Naive algorithm)");
+    let png_path = format!("benchmarks/benchmark_{:03}.png", bench_num);
+    let json_path = format!("benchmarks/benchmark_{:03}.json", bench_num);
+
+    println!(
+        "Benchmarking MLE evaluation strategies: {}",
+        STRATEGIES
+            .iter()
+            .map(|&s| strategy_name(s))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
     println!("Dimensions: {} to {}", min_dim, max_dim);
-    println!("Runs per dimension: {}", num_runs);
-    println!("Output: {}", output_path);
+    println!("Runs per (dim, strategy): {}", num_runs);
+    println!("Worker threads: {}", num_workers);
+    println!("Chart: {}", png_path);
+    println!("Records: {}", json_path);
     println!();
 
-    let mut results = Vec::new();
-
-    for dim in min_dim..=max_dim {
-        let result = bench_dimension(dim, num_runs, EvaluationType::Naive);
-        println!("dim = {:2}: {:.3} ms", result.dim, result.avg_time_ms);
-        results.push(result);
+    let dims: Vec<usize> = (min_dim..=max_dim).collect();
+    let mut records = Vec::new();
+
+    for &dim in &dims {
+        for &strategy in &STRATEGIES {
+            let samples = bench_dimension(dim, strategy, num_runs, num_workers);
+            let (mean_ms, stddev_ms) = mean_and_stddev(&samples);
+
+            println!(
+                "dim = {:2}  {:>9}: {:.3} ms (stddev {:.3})",
+                dim,
+                strategy_name(strategy),
+                mean_ms,
+                stddev_ms
+            );
+
+            records.push(BenchRecord {
+                dim,
+                strategy: strategy_name(strategy).to_string(),
+                mean_ms,
+                stddev_ms,
+                samples_ms: samples,
+            });
+        }
     }
 
     println!();
     println!("Generating chart...");
-
     generate_chart(
-        &results,
-        "MLE Naive Evaluation Time vs Dimension",
-        &output_path,
+        &records,
+        &dims,
+        "MLE Evaluation Time vs Dimension",
+        &png_path,
     );
+
+    println!("Writing raw records...");
+    let json = serde_json::to_string_pretty(&records).expect("Failed to serialize records");
+    fs::write(&json_path, json).expect("Failed to write records");
 }
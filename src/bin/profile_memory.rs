@@ -13,8 +13,30 @@ use ark_test_curves::bls12_381::Fr;
 use rand::thread_rng;
 
 use multilinear_extensions::multilinear::mle::{DenseOracle, EvaluationType, MultilinearExtension};
+use multilinear_extensions::multilinear::packed::PackedDenseOracle;
 use multilinear_extensions::multilinear::traits::MLE;
 
+/// BabyBear: modulus `2^31 - 2^27 + 1`. Its canonical representation fits
+/// in 31 bits, so two elements pack into every 64-bit word -- this is the
+/// narrow field `PackedDenseOracle` targets, unlike `bls12_381::Fr` (used
+/// everywhere else in this crate), whose ~255-bit modulus is far too wide
+/// to pack into a single machine word.
+// ark-ff 0.4's `MontConfig` derive expands to an `impl` the
+// `non_local_definitions` lint flags under current rustc; there's no newer
+// 0.4.x release that fixes it, and the attribute can't go directly on the
+// derived struct without confusing the macro's own attribute parsing.
+#[allow(non_local_definitions)]
+mod babybear {
+    use ark_ff::{Fp64, MontBackend, MontConfig};
+
+    #[derive(MontConfig)]
+    #[modulus = "2013265921"]
+    #[generator = "31"]
+    pub struct BabyBearConfig;
+    pub type BabyBear = Fp64<MontBackend<BabyBearConfig, 1>>;
+}
+use babybear::BabyBear;
+
 /// Find the next available trace number in dhat_traces/
 fn next_trace_number() -> u32 {
     let trace_dir = "dhat_traces";
@@ -70,6 +92,24 @@ fn main() {
     let mle = MultilinearExtension::new(oracle, dim, EvaluationType::Naive);
     let _result = mle.evaluate(&z).expect("Failed to evaluate");
 
+    // BabyBear's 31-bit modulus packs two elements per 64-bit word, so a
+    // packed oracle can go several dimensions wider than an Fr-backed one
+    // at the same memory cost -- run one here to keep PackedDenseOracle
+    // exercised by something other than its own unit tests.
+    let packed_dim = 24;
+    println!(
+        "\n--- Creating packed oracle (BabyBear, dim = {}) ---",
+        packed_dim
+    );
+    println!("Number of points: 2^{} = {}", packed_dim, 1u64 << packed_dim);
+    let packed_oracle = PackedDenseOracle::<BabyBear, u64>::new_rand(packed_dim, &mut rng)
+        .expect("BabyBear's modulus fits in a u64 packing word");
+    let packed_z: Vec<BabyBear> = (0..packed_dim).map(|_| BabyBear::rand(&mut rng)).collect();
+
+    println!("\n--- Evaluating packed MLE ---");
+    let packed_mle = MultilinearExtension::new(packed_oracle, packed_dim, EvaluationType::Rothblum);
+    let _packed_result = packed_mle.evaluate(&packed_z).expect("Failed to evaluate");
+
     println!("\n--- Done ---");
     println!("Trace saved to: {}", trace_path);
 }
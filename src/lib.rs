@@ -0,0 +1,6 @@
+//! multilinear_extensions: algorithms for evaluating and proving statements
+//! about multilinear extensions of boolean hypercube functions.
+
+pub mod multilinear;
+pub mod sumcheck;
+pub mod zerocheck;
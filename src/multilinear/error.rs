@@ -20,6 +20,11 @@ pub enum OracleError {
     /// Point not found in the boolean hypercube map
     #[error("Point not found in boolean hypercube map")]
     PointNotFound,
+
+    /// Field's canonical representation doesn't fit in the packed oracle's
+    /// underlying word size
+    #[error("field needs {bits} bits per element, wider than the {word_bits}-bit packing word")]
+    FieldTooWideToPack { bits: u32, word_bits: u32 },
 }
 
 /// Errors that can occur when evaluating multilinear extensions
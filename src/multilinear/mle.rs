@@ -97,6 +97,65 @@ impl<F: Field> BCubeMap<F> for BCubeMapOracle<F> {
     }
 }
 
+/// Dense, index-ordered oracle: `values[n]` holds `f(to_bcube_elt(dim, n))`
+/// directly, instead of hashing boolean points. This is what the linear-time
+/// evaluation strategies fold over, since they want `f` by integer index
+/// rather than by boolean point.
+pub struct DenseOracle<F: Field> {
+    pub dim: usize,
+    points: Vec<Vec<F>>,
+    values: Vec<F>,
+}
+
+impl<F: Field> DenseOracle<F> {
+    pub fn new(dim: usize, values: Vec<F>) -> Result<DenseOracle<F>, OracleError> {
+        let num_points = 1 << dim;
+        if values.len() != num_points {
+            return Err(OracleError::IncorrectOracleSize);
+        }
+
+        let points = (0..num_points).map(|n| to_bcube_elt(dim, n)).collect();
+
+        Ok(DenseOracle {
+            dim,
+            points,
+            values,
+        })
+    }
+
+    pub fn new_rand<R: Rng>(dim: usize, rng: &mut R) -> DenseOracle<F>
+    where
+        F: UniformRand,
+    {
+        let num_points = 1 << dim;
+        let values = (0..num_points).map(|_| F::rand(rng)).collect();
+
+        Self::new(dim, values).expect("constructed exactly 2^dim values")
+    }
+}
+
+impl<F: Field> BCubeMap<F> for DenseOracle<F> {
+    fn query(&self, point: &[F]) -> Result<F, OracleError> {
+        if point.len() != self.dim {
+            return Err(OracleError::IncorrectOraclePointDimension {
+                expected: self.dim,
+                found: point.len(),
+            });
+        }
+
+        let n = bcube_elt_to_index(point)?;
+        Ok(self.values[n])
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&Vec<F>, &F)> {
+        self.points.iter().zip(self.values.iter())
+    }
+
+    fn get(&self, _dim: usize, n: usize) -> Result<F, OracleError> {
+        self.values.get(n).copied().ok_or(OracleError::PointNotFound)
+    }
+}
+
 // Now we have a map we can query. We want multiple different MLEs that use it
 // in order to evaluate differently.
 
@@ -147,14 +206,72 @@ impl<F: Field, M: BCubeMap<F>> MultilinearExtension<F, M> {
         })
     }
 
-    fn zhu(&self, _z: &[F]) -> Result<F, MLEError> {
-        // TODO: Implement Zhu's method
-        todo!("Implement Zhu's MLE evaluation")
+    // Zhu's method builds the whole eq(., z) table in a single O(2^w)
+    // pass instead of recomputing eq(b, z) from scratch (an O(w) product)
+    // for every one of the 2^w hypercube points.
+    fn zhu(&self, z: &[F]) -> Result<F, MLEError> {
+        // check that z has the right dimension
+        if z.len() != self.dim {
+            return Err(MLEError::WrongDimension {
+                expected: self.dim,
+                found: z.len(),
+            });
+        }
+
+        let table = eq_table(z);
+        let mut sum = F::zero();
+        for (n, &weight) in table.iter().enumerate() {
+            sum += self.oracle.get(self.dim, n)? * weight;
+        }
+        Ok(sum)
     }
 
-    fn rothblum(&self, _z: &[F]) -> Result<F, MLEError> {
-        // TODO: Implement Rothblum's method
-        todo!("Implement Rothblum's MLE evaluation")
+    /// Fixes the first remaining variable to `r`, returning a new dense
+    /// oracle over `{0,1}^{w-1}` whose value at `b'` is
+    /// `(1-r)*f(0,b') + r*f(1,b')`. Chaining `w` calls evaluates the MLE
+    /// one variable at a time -- exactly the per-round folding a sum-check
+    /// prover needs, and the shared primitive behind `EvaluationType::Rothblum`.
+    pub fn fix_first_variable(&self, r: F) -> Result<MultilinearExtension<F, DenseOracle<F>>, MLEError> {
+        if self.dim == 0 {
+            return Err(MLEError::WrongDimension {
+                expected: 1,
+                found: 0,
+            });
+        }
+
+        let half = 1 << (self.dim - 1);
+        let mut values = Vec::with_capacity(half);
+        for j in 0..half {
+            let f0 = self.oracle.get(self.dim, 2 * j)?;
+            let f1 = self.oracle.get(self.dim, 2 * j + 1)?;
+            values.push((F::one() - r) * f0 + r * f1);
+        }
+
+        let oracle = DenseOracle::new(self.dim - 1, values)?;
+        Ok(MultilinearExtension::new(oracle, self.dim - 1, self.strategy))
+    }
+
+    // Rothblum's method folds one variable at a time via fix_first_variable,
+    // reusing the shrinking table instead of recomputing eq(b, z) per point.
+    fn rothblum(&self, z: &[F]) -> Result<F, MLEError> {
+        // check that z has the right dimension
+        if z.len() != self.dim {
+            return Err(MLEError::WrongDimension {
+                expected: self.dim,
+                found: z.len(),
+            });
+        }
+
+        if self.dim == 0 {
+            return Ok(self.oracle.get(0, 0)?);
+        }
+
+        let mut folded = self.fix_first_variable(z[0])?;
+        for &r in &z[1..] {
+            folded = folded.fix_first_variable(r)?;
+        }
+
+        Ok(folded.oracle.get(0, 0)?)
     }
 
     fn ramakrishna(&self, _z: &[F]) -> Result<F, MLEError> {
@@ -191,6 +308,36 @@ pub fn eq<F: Field>(b: &[F], z: &[F]) -> Result<F, MLEError> {
         .product())
 }
 
+/// builds the dense table `table[n] == eq(to_bcube_elt(dim, n), z)` in
+/// O(2^dim), by doubling the table one coordinate at a time instead of
+/// recomputing eq(b, z) from scratch for every hypercube point. `to_bcube_elt`
+/// puts `z_i` in bit `i` of `n` (least significant first), so each round's
+/// new coordinate must become the *high* half of the doubled table, not
+/// interleaved: `next[0..half] = table*(1-z_i)`, `next[half..] = table*z_i`.
+fn eq_table<F: Field>(z: &[F]) -> Vec<F> {
+    let mut table = vec![F::one()];
+    for &z_i in z {
+        let mut next = Vec::with_capacity(table.len() * 2);
+        next.extend(table.iter().map(|&t| t * (F::one() - z_i)));
+        next.extend(table.iter().map(|&t| t * z_i));
+        table = next;
+    }
+    table
+}
+
+/// the multilinear extension of `eq(., z)` as a dense oracle:
+/// `values[n] = eq(to_bcube_elt(dim, n), z)`. Used by zero-check to build
+/// the virtual polynomial `eq(b, alpha)*f(b)`.
+pub fn eq_oracle<F: Field>(dim: usize, z: &[F]) -> Result<DenseOracle<F>, MLEError> {
+    if z.len() != dim {
+        return Err(MLEError::WrongDimension {
+            expected: dim,
+            found: z.len(),
+        });
+    }
+    Ok(DenseOracle::new(dim, eq_table(z))?)
+}
+
 /// helper function that takes an int and returns the
 /// boolean hypercube element, where the hypercube is a
 /// subset of F^dim
@@ -205,3 +352,60 @@ pub fn to_bcube_elt<F: Field>(dim: usize, n: usize) -> Vec<F> {
         })
         .collect()
 }
+
+/// inverse of `to_bcube_elt`: recovers the integer whose bits (least
+/// significant first) match a boolean hypercube point's 0/1 coordinates
+pub(crate) fn bcube_elt_to_index<F: Field>(point: &[F]) -> Result<usize, OracleError> {
+    let mut n = 0usize;
+    for (i, &coord) in point.iter().enumerate() {
+        if coord == F::one() {
+            n |= 1 << i;
+        } else if coord != F::zero() {
+            return Err(OracleError::NonbooleanOraclePoint);
+        }
+    }
+    Ok(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_test_curves::bls12_381::Fr;
+    use rand::thread_rng;
+
+    use super::*;
+
+    #[test]
+    fn naive_zhu_rothblum_agree() {
+        let dim = 5;
+        let mut rng = thread_rng();
+        let values: Vec<Fr> = (0..1 << dim).map(|_| Fr::rand(&mut rng)).collect();
+        let z: Vec<Fr> = (0..dim).map(|_| Fr::rand(&mut rng)).collect();
+
+        let naive = MultilinearExtension::new(
+            DenseOracle::new(dim, values.clone()).unwrap(),
+            dim,
+            EvaluationType::Naive,
+        )
+        .evaluate(&z)
+        .unwrap();
+
+        let zhu = MultilinearExtension::new(
+            DenseOracle::new(dim, values.clone()).unwrap(),
+            dim,
+            EvaluationType::Zhu,
+        )
+        .evaluate(&z)
+        .unwrap();
+
+        let rothblum = MultilinearExtension::new(
+            DenseOracle::new(dim, values).unwrap(),
+            dim,
+            EvaluationType::Rothblum,
+        )
+        .evaluate(&z)
+        .unwrap();
+
+        assert_eq!(naive, zhu, "zhu disagreed with naive");
+        assert_eq!(naive, rothblum, "rothblum disagreed with naive");
+    }
+}
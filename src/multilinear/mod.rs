@@ -0,0 +1,9 @@
+//! Multilinear extension of a mapping from the boolean hypercube to a
+//! finite field, and the different strategies for evaluating it.
+
+pub mod error;
+pub mod mle;
+pub mod packed;
+pub mod traits;
+pub mod underlier;
+pub mod virtual_poly;
@@ -0,0 +1,190 @@
+//! Dense, bit-packed oracle for small prime fields.
+//!
+//! Storing one full `F` per hypercube point (as `DenseOracle` or
+//! `BCubeMapOracle` do) wastes memory when `F`'s canonical representation
+//! is much narrower than a machine word. `PackedDenseOracle` instead packs
+//! `F::MODULUS_BIT_SIZE` bits per evaluation into a flat `Vec<U>` of
+//! machine words, unpacking one element at a time on query. `zhu` and
+//! `rothblum` already read oracles through the index-ordered `get` path,
+//! so they run against a packed oracle unmodified.
+//!
+//! This only covers `PrimeField`s whose modulus fits in a single `U::BITS`
+//! word (`F::from(limb)`/`into_bigint().as_ref()[0]` assume one limb) --
+//! not GF(2) or binary-tower extension fields, which aren't `PrimeField`s
+//! at all. `ark_test_curves::bls12_381::Fr`, used everywhere else in this
+//! crate, is far too wide to pack (its modulus is ~255 bits), so
+//! `PackedDenseOracle::new` rejects it with `OracleError::FieldTooWideToPack`
+//! -- exercising the happy path needs a narrower field, which is what the
+//! test module below and `src/bin/profile_memory.rs` use "BabyBear" for.
+
+use std::cell::OnceCell;
+use std::marker::PhantomData;
+
+use ark_ff::{PrimeField, UniformRand};
+use rand::Rng;
+
+use super::error::OracleError;
+use super::mle::{bcube_elt_to_index, to_bcube_elt};
+use super::traits::BCubeMap;
+use super::underlier::Underlier;
+
+/// Dense, bit-packed, index-ordered oracle over `{0,1}^dim`. See the module
+/// docs for the packing rationale and its narrow-prime-field scope.
+///
+/// Packing assumes `F`'s canonical representation fits in a single 64-bit
+/// limb; constructing one over a wider field is rejected (via
+/// `OracleError::FieldTooWideToPack`) rather than silently truncated.
+pub struct PackedDenseOracle<F: PrimeField, U: Underlier> {
+    pub dim: usize,
+    bits_per_element: u32,
+    elements_per_word: u32,
+    words: Vec<U>,
+    // iter() must hand back (&Vec<F>, &F) pairs, so boolean points and
+    // values are unpacked and cached lazily the first time it's called.
+    unpacked: OnceCell<Vec<(Vec<F>, F)>>,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: PrimeField, U: Underlier> PackedDenseOracle<F, U> {
+    pub fn new(dim: usize, values: &[F]) -> Result<Self, OracleError> {
+        let num_points = 1 << dim;
+        if values.len() != num_points {
+            return Err(OracleError::IncorrectOracleSize);
+        }
+
+        let bits_per_element = F::MODULUS_BIT_SIZE;
+        if bits_per_element > U::BITS {
+            return Err(OracleError::FieldTooWideToPack {
+                bits: bits_per_element,
+                word_bits: U::BITS,
+            });
+        }
+
+        let elements_per_word = (U::BITS / bits_per_element).max(1);
+        let words_needed = num_points.div_ceil(elements_per_word as usize);
+        let mut words = vec![U::default(); words_needed];
+
+        for (n, value) in values.iter().enumerate() {
+            let word_idx = n / elements_per_word as usize;
+            let slot = (n % elements_per_word as usize) as u32;
+            let offset = slot * bits_per_element;
+            let limb = value.into_bigint().as_ref()[0];
+            words[word_idx].set_bits(offset, bits_per_element, limb);
+        }
+
+        Ok(PackedDenseOracle {
+            dim,
+            bits_per_element,
+            elements_per_word,
+            words,
+            unpacked: OnceCell::new(),
+            _phantom: PhantomData,
+        })
+    }
+
+    pub fn new_rand<R: Rng>(dim: usize, rng: &mut R) -> Result<Self, OracleError>
+    where
+        F: UniformRand,
+    {
+        let values: Vec<F> = (0..1 << dim).map(|_| F::rand(rng)).collect();
+        Self::new(dim, &values)
+    }
+
+    fn get_index(&self, n: usize) -> F {
+        let word_idx = n / self.elements_per_word as usize;
+        let slot = (n % self.elements_per_word as usize) as u32;
+        let offset = slot * self.bits_per_element;
+        let limb = self.words[word_idx].get_bits(offset, self.bits_per_element);
+        F::from(limb)
+    }
+}
+
+impl<F: PrimeField, U: Underlier> BCubeMap<F> for PackedDenseOracle<F, U> {
+    fn query(&self, point: &[F]) -> Result<F, OracleError> {
+        if point.len() != self.dim {
+            return Err(OracleError::IncorrectOraclePointDimension {
+                expected: self.dim,
+                found: point.len(),
+            });
+        }
+
+        let n = bcube_elt_to_index(point)?;
+        Ok(self.get_index(n))
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&Vec<F>, &F)> {
+        let cache = self.unpacked.get_or_init(|| {
+            (0..1usize << self.dim)
+                .map(|n| (to_bcube_elt(self.dim, n), self.get_index(n)))
+                .collect()
+        });
+        cache.iter().map(|(b, f)| (b, f))
+    }
+
+    fn get(&self, _dim: usize, n: usize) -> Result<F, OracleError> {
+        Ok(self.get_index(n))
+    }
+}
+
+/// BabyBear: modulus `2^31 - 2^27 + 1`, narrow enough (31 bits) to pack two
+/// elements per `u64` word -- unlike `bls12_381::Fr`, used everywhere else
+/// in this crate, which only exercises the rejection path below.
+// ark-ff 0.4's `MontConfig` derive expands to an `impl` the
+// `non_local_definitions` lint flags under current rustc; there's no newer
+// 0.4.x release that fixes it, and the attribute can't go directly on the
+// derived struct without confusing the macro's own attribute parsing.
+#[cfg(test)]
+#[allow(non_local_definitions)]
+mod babybear {
+    use ark_ff::{Fp64, MontBackend, MontConfig};
+
+    #[derive(MontConfig)]
+    #[modulus = "2013265921"]
+    #[generator = "31"]
+    pub struct BabyBearConfig;
+    pub type BabyBear = Fp64<MontBackend<BabyBearConfig, 1>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_test_curves::bls12_381::Fr;
+    use rand::thread_rng;
+
+    use super::babybear::BabyBear;
+    use super::*;
+
+    #[test]
+    fn field_wider_than_word_is_rejected() {
+        // bls12_381::Fr's modulus is ~255 bits -- far wider than a u64 word.
+        let values = vec![Fr::from(1u64), Fr::from(2u64)];
+        let result = PackedDenseOracle::<Fr, u64>::new(1, &values).map(|_| ());
+        assert_eq!(
+            result,
+            Err(OracleError::FieldTooWideToPack {
+                bits: Fr::MODULUS_BIT_SIZE,
+                word_bits: <u64 as Underlier>::BITS,
+            })
+        );
+    }
+
+    #[test]
+    fn pack_unpack_round_trip() {
+        let dim = 6;
+        let mut rng = thread_rng();
+        let values: Vec<BabyBear> = (0..1 << dim).map(|_| BabyBear::rand(&mut rng)).collect();
+
+        let oracle = PackedDenseOracle::<BabyBear, u64>::new(dim, &values)
+            .expect("BabyBear's 31-bit modulus fits in a u64 word");
+        assert_eq!(oracle.elements_per_word, 2);
+
+        for (n, &expected) in values.iter().enumerate() {
+            assert_eq!(oracle.get(dim, n).unwrap(), expected);
+
+            let point = to_bcube_elt(dim, n);
+            assert_eq!(oracle.query(&point).unwrap(), expected);
+        }
+
+        let from_iter: Vec<BabyBear> = oracle.iter().map(|(_, &f)| f).collect();
+        assert_eq!(from_iter, values);
+    }
+}
@@ -19,6 +19,15 @@ pub trait BCubeMap<F: Field> {
     // return iterator so points of the boolean hypercube don't have
     // to be reconstructed in order to be queried
     fn iter(&self) -> impl Iterator<Item = (&Vec<F>, &F)>;
+
+    // index-ordered access: returns f(to_bcube_elt(dim, n)). Strategies
+    // like Zhu's linear-time evaluation walk the hypercube by integer
+    // index rather than by boolean point, so oracles that already store
+    // their evaluations in index order (e.g. DenseOracle) should override
+    // this with an O(1) lookup. The default falls back to query.
+    fn get(&self, dim: usize, n: usize) -> Result<F, OracleError> {
+        self.query(&super::mle::to_bcube_elt(dim, n))
+    }
 }
 
 /// This is a multilinear extension of a mapping from the boolean
@@ -0,0 +1,49 @@
+//! Machine-word abstraction used by `PackedDenseOracle` to pack several
+//! sub-word field elements into a single word instead of storing one full
+//! `F` per hypercube point.
+
+/// A machine word that can hold several small field elements packed side
+/// by side. Implemented for the built-in unsigned integer types.
+pub trait Underlier: Copy + Default {
+    /// number of bits in one word
+    const BITS: u32;
+
+    /// read the `width`-bit field starting at bit `offset`
+    fn get_bits(&self, offset: u32, width: u32) -> u64;
+
+    /// overwrite the `width`-bit field starting at bit `offset`
+    fn set_bits(&mut self, offset: u32, width: u32, value: u64);
+}
+
+/// all-ones mask covering the low `width` bits (width may be up to 64)
+fn mask_of_width(width: u32) -> u64 {
+    if width >= 64 {
+        u64::MAX
+    } else {
+        (1u64 << width) - 1
+    }
+}
+
+macro_rules! impl_underlier {
+    ($t:ty) => {
+        impl Underlier for $t {
+            const BITS: u32 = <$t>::BITS;
+
+            fn get_bits(&self, offset: u32, width: u32) -> u64 {
+                let mask = mask_of_width(width);
+                ((*self as u64) >> offset) & mask
+            }
+
+            fn set_bits(&mut self, offset: u32, width: u32, value: u64) {
+                let mask = mask_of_width(width);
+                let cleared = (*self as u64) & !(mask << offset);
+                *self = (cleared | ((value & mask) << offset)) as $t;
+            }
+        }
+    };
+}
+
+impl_underlier!(u8);
+impl_underlier!(u16);
+impl_underlier!(u32);
+impl_underlier!(u64);
@@ -0,0 +1,202 @@
+//! Virtual polynomials: linear combinations of products of several MLEs
+//! sharing the same boolean hypercube dimension, e.g. `c1*f*g + c2*h`. This
+//! is the object sum-check actually runs on in practice -- a single MLE is
+//! just the one-term, one-factor special case.
+
+use ark_ff::Field;
+
+use super::error::MLEError;
+use super::mle::{DenseOracle, MultilinearExtension};
+use super::traits::{BCubeMap, MLE};
+
+/// Object-safe view of a `MultilinearExtension` that hides its oracle type,
+/// so a `VirtualPolynomial` can hold several MLEs with different concrete
+/// oracle backends side by side.
+pub trait DynMLE<F: Field> {
+    /// dimension of the boolean hypercube domain
+    fn dim(&self) -> usize;
+
+    /// the MLE's value at an arbitrary point in F^dim
+    fn evaluate(&self, z: &[F]) -> Result<F, MLEError>;
+
+    /// the underlying oracle's value at hypercube index n, i.e.
+    /// f(to_bcube_elt(dim, n))
+    fn get(&self, n: usize) -> Result<F, MLEError>;
+
+    /// fold this MLE's first remaining variable to r (see
+    /// `MultilinearExtension::fix_first_variable`)
+    fn fix_first_variable(&self, r: F) -> Result<MultilinearExtension<F, DenseOracle<F>>, MLEError>;
+}
+
+impl<F: Field, M: BCubeMap<F>> DynMLE<F> for MultilinearExtension<F, M> {
+    fn dim(&self) -> usize {
+        self.dim
+    }
+
+    fn evaluate(&self, z: &[F]) -> Result<F, MLEError> {
+        MLE::evaluate(self, z)
+    }
+
+    fn get(&self, n: usize) -> Result<F, MLEError> {
+        Ok(self.oracle.get(self.dim, n)?)
+    }
+
+    fn fix_first_variable(&self, r: F) -> Result<MultilinearExtension<F, DenseOracle<F>>, MLEError> {
+        MultilinearExtension::fix_first_variable(self, r)
+    }
+}
+
+/// A coefficient times the product of a subset of a `VirtualPolynomial`'s
+/// constituent MLEs, referenced by index into `VirtualPolynomial::mle`.
+#[derive(Clone)]
+pub struct ProductTerm<F: Field> {
+    pub coefficient: F,
+    pub mle_indices: Vec<usize>,
+}
+
+/// `sum_t coefficient_t * prod_{i in mle_indices_t} mles[i]`, over several
+/// MLEs of the same dimension.
+pub struct VirtualPolynomial<F: Field> {
+    pub dim: usize,
+    pub terms: Vec<ProductTerm<F>>,
+    mles: Vec<Box<dyn DynMLE<F>>>,
+}
+
+impl<F: Field> VirtualPolynomial<F> {
+    pub fn new(dim: usize) -> Self {
+        VirtualPolynomial {
+            dim,
+            terms: Vec::new(),
+            mles: Vec::new(),
+        }
+    }
+
+    /// register a constituent MLE, returning the index used to reference it
+    /// from a product term
+    pub fn add_mle<M>(&mut self, mle: MultilinearExtension<F, M>) -> Result<usize, MLEError>
+    where
+        M: BCubeMap<F> + 'static,
+        F: 'static,
+    {
+        if mle.dim != self.dim {
+            return Err(MLEError::WrongDimension {
+                expected: self.dim,
+                found: mle.dim,
+            });
+        }
+        self.mles.push(Box::new(mle));
+        Ok(self.mles.len() - 1)
+    }
+
+    /// add `coefficient * prod_{i in mle_indices} mles[i]` as a product term
+    pub fn add_product(&mut self, coefficient: F, mle_indices: Vec<usize>) {
+        self.terms.push(ProductTerm {
+            coefficient,
+            mle_indices,
+        });
+    }
+
+    pub(crate) fn mle(&self, idx: usize) -> &dyn DynMLE<F> {
+        self.mles[idx].as_ref()
+    }
+
+    /// the largest number of factors in any product term -- the virtual
+    /// polynomial's degree in each variable, and one less than the number
+    /// of evaluations a sum-check round polynomial over it must carry.
+    /// Never below 1, so a round polynomial always has at least the usual
+    /// two (s_i(0), s_i(1)) evaluations.
+    pub fn degree(&self) -> usize {
+        self.terms
+            .iter()
+            .map(|t| t.mle_indices.len())
+            .max()
+            .unwrap_or(1)
+            .max(1)
+    }
+
+    /// evaluate the virtual polynomial at an arbitrary point in F^dim
+    pub fn evaluate(&self, z: &[F]) -> Result<F, MLEError> {
+        let mut total = F::zero();
+        for term in &self.terms {
+            let mut product = term.coefficient;
+            for &idx in &term.mle_indices {
+                product *= self.mles[idx].evaluate(z)?;
+            }
+            total += product;
+        }
+        Ok(total)
+    }
+
+    /// sum over the boolean hypercube, reading constituent MLEs' underlying
+    /// oracles directly (`get`) rather than paying for a full MLE
+    /// evaluation at every one of the 2^dim points
+    pub fn sum_over_hypercube(&self) -> Result<F, MLEError> {
+        let mut total = F::zero();
+        for n in 0..(1usize << self.dim) {
+            for term in &self.terms {
+                let mut product = term.coefficient;
+                for &idx in &term.mle_indices {
+                    product *= self.mles[idx].get(n)?;
+                }
+                total += product;
+            }
+        }
+        Ok(total)
+    }
+
+    /// fold every constituent MLE's first remaining variable to `r`,
+    /// yielding a virtual polynomial over `{0,1}^{dim-1}` with the same
+    /// product terms
+    pub fn fix_first_variable(&self, r: F) -> Result<VirtualPolynomial<F>, MLEError> {
+        if self.dim == 0 {
+            return Err(MLEError::WrongDimension {
+                expected: 1,
+                found: 0,
+            });
+        }
+
+        let mles = self
+            .mles
+            .iter()
+            .map(|mle| {
+                mle.fix_first_variable(r)
+                    .map(|folded| Box::new(folded) as Box<dyn DynMLE<F>>)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(VirtualPolynomial {
+            dim: self.dim - 1,
+            terms: self.terms.clone(),
+            mles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_test_curves::bls12_381::Fr;
+    use rand::thread_rng;
+
+    use super::*;
+    use crate::multilinear::mle::{to_bcube_elt, DenseOracle, EvaluationType};
+
+    #[test]
+    fn sum_over_hypercube_matches_brute_force_evaluate() {
+        let dim = 4;
+        let mut rng = thread_rng();
+
+        let f = MultilinearExtension::new(DenseOracle::new_rand(dim, &mut rng), dim, EvaluationType::Naive);
+        let g = MultilinearExtension::new(DenseOracle::new_rand(dim, &mut rng), dim, EvaluationType::Naive);
+
+        let mut vp = VirtualPolynomial::new(dim);
+        let f_idx = vp.add_mle(f).unwrap();
+        let g_idx = vp.add_mle(g).unwrap();
+        vp.add_product(Fr::from(3u64), vec![f_idx, g_idx]);
+
+        let expected: Fr = (0..1usize << dim)
+            .map(|n| vp.evaluate(&to_bcube_elt(dim, n)).unwrap())
+            .sum();
+
+        assert_eq!(vp.sum_over_hypercube().unwrap(), expected);
+    }
+}
@@ -0,0 +1,25 @@
+//! Error types for the sum-check protocol
+
+use thiserror::Error;
+
+use crate::multilinear::error::MLEError;
+
+/// Errors that can occur while proving or verifying a sum-check instance
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum SumcheckError {
+    /// round's two endpoint evaluations don't sum to the previous claim
+    #[error("round {round}: s_i(0) + s_i(1) does not match the claimed sum")]
+    RoundSumMismatch { round: usize },
+
+    /// the final oracle query disagreed with the last round's claim
+    #[error("final MLE query does not match the last round polynomial")]
+    FinalCheckFailed,
+
+    /// proof did not contain exactly one round polynomial per variable
+    #[error("proof has {found} round polynomials, expected {expected}")]
+    WrongNumberOfRounds { expected: usize, found: usize },
+
+    /// error while evaluating the underlying multilinear extension
+    #[error("MLE error: {0}")]
+    MLE(#[from] MLEError),
+}
@@ -0,0 +1,87 @@
+//! The sum-check protocol: prove that a claimed sum of an oracle's values
+//! over the boolean hypercube is correct, one round per variable, without
+//! the verifier ever summing 2^w terms itself.
+
+pub mod error;
+pub mod prover;
+pub mod transcript;
+pub mod verifier;
+
+pub use error::SumcheckError;
+pub use prover::{RoundPolynomial, Sumcheck, SumcheckProof};
+
+#[cfg(test)]
+mod tests {
+    use ark_test_curves::bls12_381::Fr;
+    use rand::thread_rng;
+
+    use crate::multilinear::mle::{DenseOracle, EvaluationType, MultilinearExtension};
+    use crate::multilinear::virtual_poly::VirtualPolynomial;
+
+    use super::Sumcheck;
+
+    fn random_mle(dim: usize, strategy: EvaluationType) -> MultilinearExtension<Fr, DenseOracle<Fr>> {
+        let mut rng = thread_rng();
+        let oracle = DenseOracle::new_rand(dim, &mut rng);
+        MultilinearExtension::new(oracle, dim, strategy)
+    }
+
+    #[test]
+    fn prove_verify_round_trip_every_strategy() {
+        for strategy in [
+            EvaluationType::Naive,
+            EvaluationType::Zhu,
+            EvaluationType::Rothblum,
+        ] {
+            let mle = random_mle(6, strategy);
+            let (_, proof) = Sumcheck::prove(&mle).expect("prove should succeed");
+            Sumcheck::verify(&mle, &proof).expect("honest proof should verify");
+        }
+    }
+
+    #[test]
+    fn tampered_proof_is_rejected() {
+        let mle = random_mle(6, EvaluationType::Zhu);
+        let (_, mut proof) = Sumcheck::prove(&mle).expect("prove should succeed");
+        proof.round_polys[0].evals[0] += Fr::from(1u64);
+        assert!(Sumcheck::verify(&mle, &proof).is_err());
+    }
+
+    fn random_virtual_polynomial(dim: usize) -> VirtualPolynomial<Fr> {
+        let f = random_mle(dim, EvaluationType::Naive);
+        let g = random_mle(dim, EvaluationType::Naive);
+
+        let mut vp = VirtualPolynomial::new(dim);
+        let f_idx = vp.add_mle(f).unwrap();
+        let g_idx = vp.add_mle(g).unwrap();
+        vp.add_product(Fr::from(2u64), vec![f_idx, g_idx]);
+        vp
+    }
+
+    #[test]
+    fn prove_verify_virtual_round_trip() {
+        let vp = random_virtual_polynomial(6);
+        let (_, proof) = Sumcheck::prove_virtual(&vp).expect("prove_virtual should succeed");
+        Sumcheck::verify_virtual(&vp, &proof).expect("honest virtual proof should verify");
+    }
+
+    #[test]
+    fn tampered_virtual_proof_is_rejected() {
+        let vp = random_virtual_polynomial(6);
+        let (_, mut proof) = Sumcheck::prove_virtual(&vp).expect("prove_virtual should succeed");
+        proof.round_polys[0].evals[0] += Fr::from(1u64);
+        assert!(Sumcheck::verify_virtual(&vp, &proof).is_err());
+    }
+
+    #[test]
+    fn prove_on_dimension_zero_mle_errors_instead_of_panicking() {
+        let mle = random_mle(0, EvaluationType::Naive);
+        assert!(Sumcheck::prove(&mle).is_err());
+    }
+
+    #[test]
+    fn prove_virtual_on_dimension_zero_errors_instead_of_panicking() {
+        let vp = random_virtual_polynomial(0);
+        assert!(Sumcheck::prove_virtual(&vp).is_err());
+    }
+}
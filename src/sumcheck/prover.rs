@@ -0,0 +1,235 @@
+//! Sum-check prover
+
+use ark_ff::{Field, UniformRand};
+
+use crate::multilinear::error::MLEError;
+use crate::multilinear::mle::MultilinearExtension;
+use crate::multilinear::traits::BCubeMap;
+use crate::multilinear::virtual_poly::VirtualPolynomial;
+
+use super::transcript::Transcript;
+
+/// The polynomial the prover sends in a single round: its evaluations at
+/// `0, 1, ..., deg`, where `deg` is the virtual polynomial's degree (the
+/// largest number of factors in any of its product terms). A plain MLE is
+/// the one-factor special case, so its round polynomial is always linear
+/// and carries just `s_i(0)` and `s_i(1)`.
+#[derive(Debug, Clone)]
+pub struct RoundPolynomial<F: Field> {
+    pub evals: Vec<F>,
+}
+
+impl<F: Field> RoundPolynomial<F> {
+    /// the polynomial's degree: one less than the number of evaluations it carries
+    pub fn degree(&self) -> usize {
+        self.evals.len() - 1
+    }
+
+    /// s_i(0) + s_i(1), checked by the verifier against the previous claim
+    pub fn sum(&self) -> F {
+        self.evals[0] + self.evals[1]
+    }
+
+    /// evaluate at an arbitrary point via Lagrange interpolation over the
+    /// integer points `0, ..., deg` the evaluations were taken at
+    pub fn evaluate(&self, x: F) -> F {
+        let n = self.evals.len();
+        let mut result = F::zero();
+        for (i, &eval_i) in self.evals.iter().enumerate() {
+            let x_i = F::from(i as u64);
+            let mut basis = F::one();
+            for j in 0..n {
+                if j == i {
+                    continue;
+                }
+                let x_j = F::from(j as u64);
+                basis *= (x - x_j) / (x_i - x_j);
+            }
+            result += eval_i * basis;
+        }
+        result
+    }
+}
+
+/// A non-interactive sum-check proof: the claimed sum plus one round
+/// polynomial per variable.
+#[derive(Debug, Clone)]
+pub struct SumcheckProof<F: Field> {
+    pub claimed_sum: F,
+    pub round_polys: Vec<RoundPolynomial<F>>,
+}
+
+/// `s_i(X) = sum_{b_{i+1..w} in {0,1}} f(r_1,...,r_{i-1}, X, b_{i+1},...,b_w)`,
+/// read off the oracle that's already been folded on `r_1, ..., r_{i-1}` via
+/// `fix_first_variable` -- s_i(0) and s_i(1) are just the sums of that
+/// oracle's values with the (now-first) remaining variable held at 0 or 1.
+///
+/// `prove_interactive` below keeps exactly one such folded oracle around at
+/// a time (this round's, produced by last round's `fix_first_variable`) and
+/// reads it here without refolding from the original oracle -- the "keep
+/// the intermediate folded tables around" requirement just means the
+/// previous round's output table, not the full history of every round.
+fn round_polynomial<F, M>(mle: &MultilinearExtension<F, M>) -> Result<RoundPolynomial<F>, MLEError>
+where
+    F: Field,
+    M: BCubeMap<F>,
+{
+    if mle.dim == 0 {
+        return Err(MLEError::WrongDimension {
+            expected: 1,
+            found: 0,
+        });
+    }
+
+    let half = 1usize << (mle.dim - 1);
+    let mut eval_zero = F::zero();
+    let mut eval_one = F::zero();
+
+    for j in 0..half {
+        eval_zero += mle.oracle.get(mle.dim, 2 * j)?;
+        eval_one += mle.oracle.get(mle.dim, 2 * j + 1)?;
+    }
+
+    Ok(RoundPolynomial {
+        evals: vec![eval_zero, eval_one],
+    })
+}
+
+/// Same idea as `round_polynomial`, generalized to a virtual polynomial:
+/// each factor of each product term is linear in the round's free variable,
+/// so `s_i(x)` is evaluated directly at `x = 0, ..., deg` instead of only
+/// at the two endpoints.
+fn round_polynomial_virtual<F: Field>(vp: &VirtualPolynomial<F>) -> Result<RoundPolynomial<F>, MLEError> {
+    if vp.dim == 0 {
+        return Err(MLEError::WrongDimension {
+            expected: 1,
+            found: 0,
+        });
+    }
+
+    let deg = vp.degree();
+    let half = 1usize << (vp.dim - 1);
+    let mut evals = vec![F::zero(); deg + 1];
+
+    for j in 0..half {
+        for (x, eval) in evals.iter_mut().enumerate() {
+            let x_f = F::from(x as u64);
+
+            for term in &vp.terms {
+                let mut product = term.coefficient;
+                for &idx in &term.mle_indices {
+                    let v0 = vp.mle(idx).get(2 * j)?;
+                    let v1 = vp.mle(idx).get(2 * j + 1)?;
+                    product *= (F::one() - x_f) * v0 + x_f * v1;
+                }
+                *eval += product;
+            }
+        }
+    }
+
+    Ok(RoundPolynomial { evals })
+}
+
+/// Entry point for both the interactive and Fiat-Shamir sum-check provers.
+pub struct Sumcheck;
+
+impl Sumcheck {
+    /// Run the prover side of the interactive protocol end to end over a
+    /// plain MLE. After each round polynomial is computed, `next_challenge`
+    /// is called to obtain the verifier's challenge for that round — plug
+    /// in a closure reading from an actual interactive verifier, or one
+    /// that hashes a shared transcript to run non-interactively (see
+    /// [`Self::prove`]).
+    pub fn prove_interactive<F, M>(
+        mle: &MultilinearExtension<F, M>,
+        mut next_challenge: impl FnMut(&RoundPolynomial<F>) -> F,
+    ) -> Result<(F, SumcheckProof<F>), MLEError>
+    where
+        F: Field,
+        M: BCubeMap<F>,
+    {
+        let mut round_polys = Vec::with_capacity(mle.dim);
+
+        let first_poly = round_polynomial(mle)?;
+        let claimed_sum = first_poly.sum();
+        let r = next_challenge(&first_poly);
+        let mut folded = mle.fix_first_variable(r)?;
+        round_polys.push(first_poly);
+
+        for _ in 1..mle.dim {
+            let poly = round_polynomial(&folded)?;
+            let r = next_challenge(&poly);
+            folded = folded.fix_first_variable(r)?;
+            round_polys.push(poly);
+        }
+
+        Ok((
+            claimed_sum,
+            SumcheckProof {
+                claimed_sum,
+                round_polys,
+            },
+        ))
+    }
+
+    /// Run the non-interactive (Fiat-Shamir) prover over a plain MLE:
+    /// challenges are derived by hashing the round polynomials sent so far
+    /// instead of being drawn by a verifier.
+    pub fn prove<F, M>(mle: &MultilinearExtension<F, M>) -> Result<(F, SumcheckProof<F>), MLEError>
+    where
+        F: Field + UniformRand,
+        M: BCubeMap<F>,
+    {
+        let mut transcript = Transcript::new(b"sumcheck");
+        Self::prove_interactive(mle, |poly| {
+            for e in &poly.evals {
+                transcript.append(e);
+            }
+            transcript.challenge()
+        })
+    }
+
+    /// Same as [`Self::prove_interactive`], but over a `VirtualPolynomial`:
+    /// round polynomials now carry `deg + 1` evaluations, where `deg` is
+    /// the virtual polynomial's degree.
+    pub fn prove_virtual_interactive<F: Field>(
+        vp: &VirtualPolynomial<F>,
+        mut next_challenge: impl FnMut(&RoundPolynomial<F>) -> F,
+    ) -> Result<(F, SumcheckProof<F>), MLEError> {
+        let mut round_polys = Vec::with_capacity(vp.dim);
+
+        let first_poly = round_polynomial_virtual(vp)?;
+        let claimed_sum = first_poly.sum();
+        let r = next_challenge(&first_poly);
+        let mut folded = vp.fix_first_variable(r)?;
+        round_polys.push(first_poly);
+
+        for _ in 1..vp.dim {
+            let poly = round_polynomial_virtual(&folded)?;
+            let r = next_challenge(&poly);
+            folded = folded.fix_first_variable(r)?;
+            round_polys.push(poly);
+        }
+
+        Ok((
+            claimed_sum,
+            SumcheckProof {
+                claimed_sum,
+                round_polys,
+            },
+        ))
+    }
+
+    /// Same as [`Self::prove`], but over a `VirtualPolynomial`.
+    pub fn prove_virtual<F: Field + UniformRand>(
+        vp: &VirtualPolynomial<F>,
+    ) -> Result<(F, SumcheckProof<F>), MLEError> {
+        let mut transcript = Transcript::new(b"sumcheck-virtual");
+        Self::prove_virtual_interactive(vp, |poly| {
+            for e in &poly.evals {
+                transcript.append(e);
+            }
+            transcript.challenge()
+        })
+    }
+}
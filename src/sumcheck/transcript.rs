@@ -0,0 +1,48 @@
+//! Fiat-Shamir transcript used to turn the interactive sum-check protocol
+//! into a non-interactive one: instead of a verifier drawing the round
+//! challenges, they're derived by hashing the messages the prover has sent
+//! so far, the same way halo2's transcript drives its own challenges.
+
+use ark_ff::{Field, UniformRand};
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use sha2::{Digest, Sha256};
+
+/// Running hash of every prover message absorbed so far. Squeezing a
+/// challenge ratchets the internal state so the same challenge is never
+/// produced twice from the same transcript.
+pub struct Transcript {
+    state: Sha256,
+}
+
+impl Transcript {
+    /// start a fresh transcript, seeded with a domain separator so that
+    /// transcripts for different protocols never collide
+    pub fn new(label: &[u8]) -> Self {
+        let mut state = Sha256::new();
+        state.update(label);
+        Transcript { state }
+    }
+
+    /// absorb a field element emitted by the prover
+    pub fn append<F: Field>(&mut self, elt: &F) {
+        let mut bytes = Vec::new();
+        elt.serialize_compressed(&mut bytes)
+            .expect("field element serialization cannot fail");
+        self.state.update(&bytes);
+    }
+
+    /// squeeze a verifier challenge out of the transcript
+    pub fn challenge<F: Field + UniformRand>(&mut self) -> F {
+        let digest = self.state.clone().finalize();
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&digest);
+
+        // ratchet the state so replaying the same messages never yields the
+        // same challenge twice in a row
+        self.state.update(b"challenge");
+
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        F::rand(&mut rng)
+    }
+}
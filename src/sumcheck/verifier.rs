@@ -0,0 +1,122 @@
+//! Sum-check verifier
+
+use ark_ff::{Field, UniformRand};
+
+use crate::multilinear::mle::MultilinearExtension;
+use crate::multilinear::traits::{BCubeMap, MLE};
+use crate::multilinear::virtual_poly::VirtualPolynomial;
+
+use super::error::SumcheckError;
+use super::prover::{RoundPolynomial, Sumcheck, SumcheckProof};
+use super::transcript::Transcript;
+
+impl Sumcheck {
+    /// Verify a sum-check proof over a plain MLE, deriving each round's
+    /// challenge via `next_challenge` — supplied by an interactive
+    /// verifier, or replayed from the same transcript the prover used (see
+    /// [`Self::verify`]). The final check queries `mle.evaluate(&challenges)`
+    /// directly, since the verifier has oracle access to the same
+    /// multilinear extension.
+    pub fn verify_interactive<F, M>(
+        mle: &MultilinearExtension<F, M>,
+        proof: &SumcheckProof<F>,
+        mut next_challenge: impl FnMut(&RoundPolynomial<F>) -> F,
+    ) -> Result<(), SumcheckError>
+    where
+        F: Field,
+        M: BCubeMap<F>,
+    {
+        if proof.round_polys.len() != mle.dim {
+            return Err(SumcheckError::WrongNumberOfRounds {
+                expected: mle.dim,
+                found: proof.round_polys.len(),
+            });
+        }
+
+        let mut claim = proof.claimed_sum;
+        let mut challenges = Vec::with_capacity(mle.dim);
+
+        for (round, poly) in proof.round_polys.iter().enumerate() {
+            if poly.sum() != claim {
+                return Err(SumcheckError::RoundSumMismatch { round });
+            }
+            let r = next_challenge(poly);
+            claim = poly.evaluate(r);
+            challenges.push(r);
+        }
+
+        let final_value = mle.evaluate(&challenges)?;
+        if final_value != claim {
+            return Err(SumcheckError::FinalCheckFailed);
+        }
+
+        Ok(())
+    }
+
+    /// Verify a non-interactive (Fiat-Shamir) proof over a plain MLE, by
+    /// replaying the same transcript the prover used to derive its
+    /// challenges.
+    pub fn verify<F, M>(
+        mle: &MultilinearExtension<F, M>,
+        proof: &SumcheckProof<F>,
+    ) -> Result<(), SumcheckError>
+    where
+        F: Field + UniformRand,
+        M: BCubeMap<F>,
+    {
+        let mut transcript = Transcript::new(b"sumcheck");
+        Self::verify_interactive(mle, proof, |poly| {
+            for e in &poly.evals {
+                transcript.append(e);
+            }
+            transcript.challenge()
+        })
+    }
+
+    /// Same as [`Self::verify_interactive`], but over a `VirtualPolynomial`.
+    pub fn verify_virtual_interactive<F: Field>(
+        vp: &VirtualPolynomial<F>,
+        proof: &SumcheckProof<F>,
+        mut next_challenge: impl FnMut(&RoundPolynomial<F>) -> F,
+    ) -> Result<(), SumcheckError> {
+        if proof.round_polys.len() != vp.dim {
+            return Err(SumcheckError::WrongNumberOfRounds {
+                expected: vp.dim,
+                found: proof.round_polys.len(),
+            });
+        }
+
+        let mut claim = proof.claimed_sum;
+        let mut challenges = Vec::with_capacity(vp.dim);
+
+        for (round, poly) in proof.round_polys.iter().enumerate() {
+            if poly.sum() != claim {
+                return Err(SumcheckError::RoundSumMismatch { round });
+            }
+            let r = next_challenge(poly);
+            claim = poly.evaluate(r);
+            challenges.push(r);
+        }
+
+        let final_value = vp.evaluate(&challenges)?;
+        if final_value != claim {
+            return Err(SumcheckError::FinalCheckFailed);
+        }
+
+        Ok(())
+    }
+
+    /// Same as [`Self::verify`], but over a `VirtualPolynomial`.
+    pub fn verify_virtual<F: Field + UniformRand>(
+        vp: &VirtualPolynomial<F>,
+        proof: &SumcheckProof<F>,
+    ) -> Result<(), SumcheckError> {
+        let mut transcript = Transcript::new(b"sumcheck-virtual");
+        Self::verify_virtual_interactive(vp, proof, |poly| {
+            for e in &poly.evals {
+                transcript.append(e);
+            }
+            transcript.challenge()
+        })
+    }
+}
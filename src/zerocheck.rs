@@ -0,0 +1,237 @@
+//! Zero-check: proves `f(b) = 0` for every `b` in `{0,1}^w` without the
+//! verifier scanning all `2^w` points. Standard reduction: the verifier
+//! samples a random vector `alpha`, and both parties run sum-check on the
+//! virtual polynomial `g(b) = eq(b, alpha)*f(b)`, claiming the sum is zero.
+//! `sum_b eq(b,alpha)*f(b)` is the MLE of `f` evaluated at `alpha`, which is
+//! zero with high probability over the choice of `alpha` only if `f`
+//! vanishes on the whole cube.
+
+use ark_ff::{Field, UniformRand};
+
+use crate::multilinear::error::MLEError;
+use crate::multilinear::mle::{eq_oracle, EvaluationType, MultilinearExtension};
+use crate::multilinear::traits::BCubeMap;
+use crate::multilinear::virtual_poly::VirtualPolynomial;
+use crate::sumcheck::transcript::Transcript;
+use crate::sumcheck::{RoundPolynomial, Sumcheck, SumcheckError, SumcheckProof};
+
+/// A zero-check proof: the sampled randomness `alpha` plus the sum-check
+/// proof that `sum_b eq(b, alpha)*f(b) = 0`.
+///
+/// In the interactive protocol `alpha` is the verifier's own randomness, so
+/// `verify_interactive` trusts it as given. In the non-interactive protocol
+/// it is instead re-derived by `verify` from `f` (see
+/// [`Zerocheck::sample_alpha`]); this field still carries the prover's
+/// derivation for inspection, but `verify` never reads it, since a prover
+/// that got to pick `alpha` freely could choose a root of `MLE_f` and force
+/// a false accept over a nonzero `f`.
+#[derive(Debug, Clone)]
+pub struct ZerocheckProof<F: Field> {
+    pub alpha: Vec<F>,
+    pub sumcheck_proof: SumcheckProof<F>,
+}
+
+/// Entry point for both the interactive and Fiat-Shamir zero-check provers.
+pub struct Zerocheck;
+
+impl Zerocheck {
+    /// g(b) = eq(b, alpha)*f(b), as the two-MLE, one-product virtual
+    /// polynomial sum-check actually runs on.
+    fn virtual_polynomial<F, M>(
+        mle: MultilinearExtension<F, M>,
+        alpha: &[F],
+    ) -> Result<VirtualPolynomial<F>, MLEError>
+    where
+        F: Field + 'static,
+        M: BCubeMap<F> + 'static,
+    {
+        let dim = mle.dim;
+        let eq_mle = MultilinearExtension::new(eq_oracle(dim, alpha)?, dim, EvaluationType::Zhu);
+
+        let mut vp = VirtualPolynomial::new(dim);
+        let eq_idx = vp.add_mle(eq_mle)?;
+        let f_idx = vp.add_mle(mle)?;
+        vp.add_product(F::one(), vec![eq_idx, f_idx]);
+
+        Ok(vp)
+    }
+
+    /// Derive `alpha` from `f` itself via Fiat-Shamir, by absorbing every
+    /// one of `f`'s `2^dim` evaluations into a transcript before squeezing
+    /// `dim` challenges. Binding `alpha` to `f` this way (instead of
+    /// letting the prover pick it, or deriving it from nothing) is what
+    /// makes the non-interactive protocol sound: `MLE_f` is linear in each
+    /// variable, so a prover that got to choose `alpha` freely could always
+    /// solve for a root of `MLE_f` and force a false accept over a
+    /// nonzero `f`.
+    fn sample_alpha<F, M>(mle: &MultilinearExtension<F, M>) -> Result<Vec<F>, MLEError>
+    where
+        F: Field + UniformRand,
+        M: BCubeMap<F>,
+    {
+        let mut transcript = Transcript::new(b"zerocheck-alpha");
+        for n in 0..(1usize << mle.dim) {
+            transcript.append(&mle.oracle.get(mle.dim, n)?);
+        }
+        Ok((0..mle.dim).map(|_| transcript.challenge()).collect())
+    }
+
+    /// Run the prover side of the interactive protocol: `alpha` is the
+    /// verifier's sampled randomness, and `next_challenge` drives the
+    /// underlying sum-check (see [`Sumcheck::prove_virtual_interactive`]).
+    pub fn prove_interactive<F, M>(
+        mle: MultilinearExtension<F, M>,
+        alpha: &[F],
+        next_challenge: impl FnMut(&RoundPolynomial<F>) -> F,
+    ) -> Result<ZerocheckProof<F>, MLEError>
+    where
+        F: Field + 'static,
+        M: BCubeMap<F> + 'static,
+    {
+        let vp = Self::virtual_polynomial(mle, alpha)?;
+        let (_, sumcheck_proof) = Sumcheck::prove_virtual_interactive(&vp, next_challenge)?;
+        Ok(ZerocheckProof {
+            alpha: alpha.to_vec(),
+            sumcheck_proof,
+        })
+    }
+
+    /// Run the non-interactive (Fiat-Shamir) prover: `alpha` is derived from
+    /// `f` via [`Self::sample_alpha`] rather than chosen by the prover, and
+    /// every sum-check challenge is derived by hashing the round
+    /// polynomials sent so far (see [`Sumcheck::prove_virtual`]).
+    pub fn prove<F, M>(mle: MultilinearExtension<F, M>) -> Result<ZerocheckProof<F>, MLEError>
+    where
+        F: Field + UniformRand + 'static,
+        M: BCubeMap<F> + 'static,
+    {
+        let alpha = Self::sample_alpha(&mle)?;
+        let vp = Self::virtual_polynomial(mle, &alpha)?;
+        let (_, sumcheck_proof) = Sumcheck::prove_virtual(&vp)?;
+        Ok(ZerocheckProof { alpha, sumcheck_proof })
+    }
+
+    /// Verify an interactive zero-check proof against `f`'s oracle.
+    pub fn verify_interactive<F, M>(
+        mle: MultilinearExtension<F, M>,
+        proof: &ZerocheckProof<F>,
+        next_challenge: impl FnMut(&RoundPolynomial<F>) -> F,
+    ) -> Result<(), SumcheckError>
+    where
+        F: Field + 'static,
+        M: BCubeMap<F> + 'static,
+    {
+        if proof.sumcheck_proof.claimed_sum != F::zero() {
+            return Err(SumcheckError::RoundSumMismatch { round: 0 });
+        }
+
+        let vp = Self::virtual_polynomial(mle, &proof.alpha)?;
+        Sumcheck::verify_virtual_interactive(&vp, &proof.sumcheck_proof, next_challenge)
+    }
+
+    /// Verify a non-interactive (Fiat-Shamir) zero-check proof. `alpha` is
+    /// re-derived from `f` via [`Self::sample_alpha`] rather than trusted
+    /// from `proof.alpha` -- the prover's claimed `alpha` is never used, so
+    /// it cannot be chosen to land on a root of `MLE_f`.
+    pub fn verify<F, M>(mle: MultilinearExtension<F, M>, proof: &ZerocheckProof<F>) -> Result<(), SumcheckError>
+    where
+        F: Field + UniformRand + 'static,
+        M: BCubeMap<F> + 'static,
+    {
+        if proof.sumcheck_proof.claimed_sum != F::zero() {
+            return Err(SumcheckError::RoundSumMismatch { round: 0 });
+        }
+
+        let alpha = Self::sample_alpha(&mle)?;
+        let vp = Self::virtual_polynomial(mle, &alpha)?;
+        Sumcheck::verify_virtual(&vp, &proof.sumcheck_proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_test_curves::bls12_381::Fr;
+
+    use crate::multilinear::mle::{DenseOracle, EvaluationType, MultilinearExtension};
+
+    use super::{Zerocheck, ZerocheckProof};
+
+    #[test]
+    fn prove_verify_round_trip_over_the_zero_polynomial() {
+        let dim = 5;
+
+        let values = vec![Fr::from(0u64); 1 << dim];
+        let mle = MultilinearExtension::new(
+            DenseOracle::new(dim, values).unwrap(),
+            dim,
+            EvaluationType::Naive,
+        );
+
+        let proof = Zerocheck::prove(mle).expect("prove should succeed");
+
+        let values = vec![Fr::from(0u64); 1 << dim];
+        let mle = MultilinearExtension::new(
+            DenseOracle::new(dim, values).unwrap(),
+            dim,
+            EvaluationType::Naive,
+        );
+        Zerocheck::verify(mle, &proof).expect("honest zero-check proof should verify");
+    }
+
+    #[test]
+    fn nonzero_polynomial_is_rejected() {
+        let dim = 5;
+
+        let values = vec![Fr::from(1u64); 1 << dim];
+        let mle = MultilinearExtension::new(
+            DenseOracle::new(dim, values.clone()).unwrap(),
+            dim,
+            EvaluationType::Naive,
+        );
+        let proof = Zerocheck::prove(mle).expect("prove should succeed");
+
+        let mle = MultilinearExtension::new(
+            DenseOracle::new(dim, values).unwrap(),
+            dim,
+            EvaluationType::Naive,
+        );
+        assert!(Zerocheck::verify(mle, &proof).is_err());
+    }
+
+    /// A malicious prover who got to pick `alpha` freely could solve for a
+    /// root of `MLE_f` and get a nonzero `f` accepted: `f` is `1` at `b=0`
+    /// and `0` everywhere else, so `MLE_f(alpha) = 1 - alpha` over `dim=1`,
+    /// which vanishes at `alpha=1` even though `f` is not the zero
+    /// polynomial. Forge a proof around that root directly (bypassing
+    /// `Zerocheck::prove`'s honest Fiat-Shamir derivation) and confirm
+    /// `verify` rejects it, since it re-derives its own `alpha` from `f`
+    /// instead of trusting `proof.alpha`.
+    #[test]
+    fn prover_chosen_root_of_mle_is_rejected() {
+        let dim = 1;
+        let values = vec![Fr::from(1u64), Fr::from(0u64)];
+        let root = vec![Fr::from(1u64)];
+
+        let mle = MultilinearExtension::new(
+            DenseOracle::new(dim, values.clone()).unwrap(),
+            dim,
+            EvaluationType::Naive,
+        );
+        let vp = Zerocheck::virtual_polynomial(mle, &root).expect("virtual polynomial should build");
+        assert_eq!(vp.sum_over_hypercube().unwrap(), Fr::from(0u64));
+
+        let (_, sumcheck_proof) =
+            crate::sumcheck::Sumcheck::prove_virtual(&vp).expect("prove_virtual should succeed");
+        let forged = ZerocheckProof {
+            alpha: root,
+            sumcheck_proof,
+        };
+
+        let mle = MultilinearExtension::new(
+            DenseOracle::new(dim, values).unwrap(),
+            dim,
+            EvaluationType::Naive,
+        );
+        assert!(Zerocheck::verify(mle, &forged).is_err());
+    }
+}